@@ -10,10 +10,35 @@
 //! 
 //! Gesture "6": Left hand open palm facing upward (all fingers extended, wrist below fingers)
 //! Gesture "7": Right hand open palm facing upward (all fingers extended, wrist below fingers)
+//!
+//! Palm orientation and handedness gating (`set_require_palm_up`, `set_require_handedness`)
+//! are opt-in: enable them once the caller can supply palm-up frames and MediaPipe
+//! handedness labels, so a mirrored or face-down hand doesn't trip the wrong count.
+
+use std::collections::VecDeque;
 
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
 
+/// How long a window of recent frames is kept for dynamic motion gesture detection.
+const MOTION_WINDOW_MS: f64 = 350.0;
+/// Minimum elapsed time within the window before a motion gesture is considered,
+/// so a couple of frames at a high frame rate can't masquerade as a swipe.
+const MOTION_MIN_WINDOW_MS: f64 = 80.0;
+/// Minimum "up-ness" (dot product with the up direction, normalized) the palm normal
+/// must have for the palm to count as facing up/toward the camera.
+const PALM_UP_TOLERANCE: f32 = 0.3;
+/// Expected range for inter-knuckle spacing, as a ratio of the wrist-to-middle-MCP
+/// reference scale. Knuckles closer or further apart than this don't look like a hand.
+const KNUCKLE_SPACING_RATIO: std::ops::RangeInclusive<f32> = 0.15..=1.2;
+/// Fingertips closer together than this fraction of the reference scale are treated
+/// as the 21 points having collapsed onto each other (degenerate landmarks).
+const MIN_FINGERTIP_SEPARATION_RATIO: f32 = 0.05;
+/// Expected bounding-box width/height ratio range for a plausible hand.
+const HAND_ASPECT_RATIO: std::ops::RangeInclusive<f32> = 0.25..=4.0;
+/// Confidence penalty multiplier applied per failed plausibility check.
+const CONFIDENCE_PENALTY: f32 = 0.5;
+
 /// Represents a single 2D/3D landmark point from MediaPipe
 #[derive(Debug, Clone, Copy, Deserialize)]
 pub struct Landmark {
@@ -53,6 +78,13 @@ pub enum GestureType {
     None,
     Six,
     Seven,
+    SwipeLeft,
+    SwipeRight,
+    SwipeUp,
+    SwipeDown,
+    PinchIn,
+    PinchOut,
+    Rotate,
 }
 
 impl GestureType {
@@ -61,10 +93,27 @@ impl GestureType {
             GestureType::None => "none",
             GestureType::Six => "six",
             GestureType::Seven => "seven",
+            GestureType::SwipeLeft => "swipe_left",
+            GestureType::SwipeRight => "swipe_right",
+            GestureType::SwipeUp => "swipe_up",
+            GestureType::SwipeDown => "swipe_down",
+            GestureType::PinchIn => "pinch_in",
+            GestureType::PinchOut => "pinch_out",
+            GestureType::Rotate => "rotate",
         }
     }
 }
 
+/// A single sample kept in the motion ring buffer: the three landmarks that dynamic
+/// gesture detection cares about, plus the timestamp they were observed at.
+#[derive(Debug, Clone, Copy)]
+struct MotionFrame {
+    timestamp_ms: f64,
+    wrist: Landmark,
+    thumb_tip: Landmark,
+    index_tip: Landmark,
+}
+
 /// Result of processing a frame
 #[derive(Debug, Serialize)]
 pub struct FrameResult {
@@ -72,6 +121,107 @@ pub struct FrameResult {
     pub state: String,
     pub count: u32,
     pub scored: bool,
+    /// Time left before an internally-timed attempt (see `start_with_duration`) fails,
+    /// or `None` when the attempt has no internal duration set or is no longer running.
+    pub remaining_ms: Option<f64>,
+    /// Hand-plausibility score in `[0, 1]` for this frame's landmarks; see `set_min_confidence`.
+    pub confidence: f32,
+    /// Extra frames of latency the lookahead suppression buffer adds before a gesture
+    /// is emitted/scored; see `set_lookahead`. `0` when lookahead is disabled.
+    pub latency_frames: u32,
+}
+
+/// Box/deadband filter: hold `prev` steady while `input` is within `radius` of it,
+/// otherwise snap to the edge of the box closest to `input`.
+fn box_filter(input: f32, prev: f32, radius: f32) -> f32 {
+    let delta = input - prev;
+    if delta.abs() <= radius {
+        prev
+    } else {
+        input - delta.signum() * radius
+    }
+}
+
+/// True if `values` is non-decreasing or non-increasing throughout (allowing a small
+/// amount of jitter), i.e. the motion never reverses direction within the window.
+fn is_monotonic(values: &[f32]) -> bool {
+    const TOLERANCE: f32 = 0.002;
+    let mut increasing = true;
+    let mut decreasing = true;
+    for pair in values.windows(2) {
+        let delta = pair[1] - pair[0];
+        if delta < -TOLERANCE {
+            increasing = false;
+        }
+        if delta > TOLERANCE {
+            decreasing = false;
+        }
+    }
+    increasing || decreasing
+}
+
+/// Euclidean distance between two landmarks in the x/y plane.
+fn dist(a: Landmark, b: Landmark) -> f32 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+/// Hand-plausibility confidence in `[0, 1]`. Uses the wrist-to-middle-MCP distance as
+/// a reference scale, then checks that inter-knuckle spacing, fingertip separation,
+/// and the overall bounding-box aspect ratio all fall within hand-like ranges,
+/// applying a penalty for each check that doesn't.
+fn compute_confidence(lm: &[Landmark]) -> f32 {
+    let scale = dist(lm[0], lm[9]); // wrist -> middle MCP
+    if scale < f32::EPSILON {
+        return 0.0;
+    }
+
+    let mut confidence = 1.0f32;
+
+    let knuckles = [lm[5], lm[9], lm[13], lm[17]]; // index, middle, ring, pinky MCPs
+    for pair in knuckles.windows(2) {
+        let ratio = dist(pair[0], pair[1]) / scale;
+        if !KNUCKLE_SPACING_RATIO.contains(&ratio) {
+            confidence *= CONFIDENCE_PENALTY;
+        }
+    }
+
+    // Deliberately excludes the thumb tip (4): `PinchIn`/`PinchOut` are defined by the
+    // thumb tip converging with another fingertip, so penalizing that proximity here
+    // would tank confidence right as a real pinch completes. The remaining four tips
+    // collapsing onto each other still catches genuinely degenerate landmarks.
+    let tips = [lm[8], lm[12], lm[16], lm[20]];
+    for i in 0..tips.len() {
+        for j in (i + 1)..tips.len() {
+            if dist(tips[i], tips[j]) / scale < MIN_FINGERTIP_SEPARATION_RATIO {
+                confidence *= CONFIDENCE_PENALTY;
+            }
+        }
+    }
+
+    let (min_x, max_x) = lm.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| (lo.min(p.x), hi.max(p.x)));
+    let (min_y, max_y) = lm.iter().fold((f32::MAX, f32::MIN), |(lo, hi), p| (lo.min(p.y), hi.max(p.y)));
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+    if width < f32::EPSILON || height < f32::EPSILON {
+        return 0.0;
+    }
+    if !HAND_ASPECT_RATIO.contains(&(width / height)) {
+        confidence *= CONFIDENCE_PENALTY;
+    }
+
+    confidence.clamp(0.0, 1.0)
+}
+
+/// Signed difference between two angles (radians), wrapped to [-pi, pi].
+fn angle_diff(from: f32, to: f32) -> f32 {
+    let mut diff = to - from;
+    while diff > std::f32::consts::PI {
+        diff -= 2.0 * std::f32::consts::PI;
+    }
+    while diff < -std::f32::consts::PI {
+        diff += 2.0 * std::f32::consts::PI;
+    }
+    diff
 }
 
 /// The main gesture gate state machine
@@ -87,6 +237,41 @@ pub struct GestureGate {
     stable_frames: u32,
     current_stable_count: u32,
     pending_gesture: GestureType,
+    /// Deadband radius (normalized coords) for the landmark jitter filter
+    smoothing_radius: f32,
+    /// Previous filtered frame, used as the `prev` value of the box filter
+    prev_landmarks: Option<Vec<Landmark>>,
+    /// Recent (wrist, thumb tip, index tip) samples within `MOTION_WINDOW_MS`
+    motion_history: VecDeque<MotionFrame>,
+    /// Minimum wrist speed (normalized units/sec) to register a swipe
+    swipe_vel_threshold: f32,
+    /// Minimum fractional change in thumb-index distance to register a pinch
+    pinch_delta_threshold: f32,
+    /// Minimum accumulated change in the thumb->index angle (radians) to register a rotate
+    rotate_angle_threshold: f32,
+    /// Total wall-clock budget for the current attempt, set by `start_with_duration`
+    duration_ms: Option<f64>,
+    /// Timestamp of the first processed frame after `start_with_duration`, used as t=0
+    start_time_ms: Option<f64>,
+    /// Time left before the internal countdown fails the attempt, reported in `FrameResult`
+    remaining_ms: Option<f64>,
+    /// Wall-clock duration a gesture must stay frame-stable before it can score.
+    /// `0.0` disables hold-to-confirm and scores as soon as `stable_frames` is reached.
+    hold_ms: f64,
+    /// Timestamp the current pending gesture first became frame-stable
+    held_since: Option<f64>,
+    /// Require the palm normal to point up/toward the camera before scoring "6"/"7"
+    require_palm_up: bool,
+    /// Require a matching MediaPipe handedness label before scoring "6" (left) / "7" (right)
+    require_handedness: bool,
+    /// Minimum hand-plausibility confidence a frame needs before it affects stability/scoring
+    min_confidence: f32,
+    /// Confidence computed for the most recently processed frame, reported in `FrameResult`
+    last_confidence: f32,
+    /// Number of extra frames a detected gesture must hold for before it's trusted
+    lookahead_frames: u32,
+    /// Sliding window of the most recent raw per-frame gestures, used by `apply_lookahead`
+    gesture_buffer: VecDeque<GestureType>,
 }
 
 #[wasm_bindgen]
@@ -103,9 +288,87 @@ impl GestureGate {
             stable_frames: 3, // Require 3 consecutive frames
             current_stable_count: 0,
             pending_gesture: GestureType::None,
+            smoothing_radius: 0.01,
+            prev_landmarks: None,
+            motion_history: VecDeque::new(),
+            swipe_vel_threshold: 0.8,
+            pinch_delta_threshold: 0.15,
+            rotate_angle_threshold: 0.4,
+            duration_ms: None,
+            start_time_ms: None,
+            remaining_ms: None,
+            hold_ms: 0.0,
+            held_since: None,
+            require_palm_up: false,
+            require_handedness: false,
+            min_confidence: 0.5,
+            last_confidence: 1.0,
+            lookahead_frames: 0,
+            gesture_buffer: VecDeque::new(),
         }
     }
 
+    /// Set the deadband radius (normalized coords) used by the landmark jitter filter.
+    /// Coordinates that move by less than `radius` from the previous filtered frame are
+    /// held steady; larger movements snap through with only a constant lag.
+    #[wasm_bindgen]
+    pub fn set_smoothing(&mut self, radius: f32) {
+        self.smoothing_radius = radius;
+    }
+
+    /// Configure the thresholds used for dynamic motion gesture detection.
+    /// `swipe_vel` is in normalized units/sec, `pinch_delta` is a fraction of the
+    /// starting thumb-index distance, `rotate_angle` is in radians.
+    #[wasm_bindgen]
+    pub fn set_motion_thresholds(&mut self, swipe_vel: f32, pinch_delta: f32, rotate_angle: f32) {
+        self.swipe_vel_threshold = swipe_vel;
+        self.pinch_delta_threshold = pinch_delta;
+        self.rotate_angle_threshold = rotate_angle;
+    }
+
+    /// Require a gesture to stay continuously frame-stable for `hold_ms` of wall-clock
+    /// time (per frame timestamps) before it scores, in addition to `stable_frames`.
+    /// Pass `0.0` to disable and score as soon as `stable_frames` is reached.
+    #[wasm_bindgen]
+    pub fn set_hold_ms(&mut self, hold_ms: f64) {
+        self.hold_ms = hold_ms;
+    }
+
+    /// Require the palm normal to point up/toward the camera before "6"/"7" can score.
+    /// The palm-normal sign is chirality-dependent: when a frame's `is_left` is
+    /// unknown (`None`), the left-hand convention is assumed, so a genuine right-hand
+    /// pose can be evaluated with the wrong chirality and wrongly rejected (or a
+    /// mirrored pose wrongly accepted). Always pass `is_left` to `process_landmarks`/
+    /// `process_raw` once this is enabled, ideally alongside `set_require_handedness`.
+    #[wasm_bindgen]
+    pub fn set_require_palm_up(&mut self, required: bool) {
+        self.require_palm_up = required;
+    }
+
+    /// Require the `is_left` handedness label passed to `process_landmarks` to match
+    /// the hand "6" (left) / "7" (right) is defined for, before it can score.
+    #[wasm_bindgen]
+    pub fn set_require_handedness(&mut self, required: bool) {
+        self.require_handedness = required;
+    }
+
+    /// Set the minimum hand-plausibility confidence (`[0, 1]`) a frame needs before
+    /// it's allowed to update stability tracking or score. Frames below this are
+    /// treated as degenerate/garbage detections and are otherwise ignored.
+    #[wasm_bindgen]
+    pub fn set_min_confidence(&mut self, min_confidence: f32) {
+        self.min_confidence = min_confidence;
+    }
+
+    /// Require a detected gesture to persist for `frames` extra frames before it's
+    /// emitted or scored, discarding it as a transient if it doesn't hold. This adds
+    /// `frames` of latency to both the reported gesture and scoring. Pass `0` to disable.
+    #[wasm_bindgen]
+    pub fn set_lookahead(&mut self, frames: u32) {
+        self.lookahead_frames = frames;
+        self.gesture_buffer.clear();
+    }
+
     /// Start the attempt
     #[wasm_bindgen]
     pub fn start(&mut self) {
@@ -115,6 +378,23 @@ impl GestureGate {
         self.can_score = true;
         self.current_stable_count = 0;
         self.pending_gesture = GestureType::None;
+        self.prev_landmarks = None;
+        self.motion_history.clear();
+        self.duration_ms = None;
+        self.start_time_ms = None;
+        self.remaining_ms = None;
+        self.held_since = None;
+        self.gesture_buffer.clear();
+    }
+
+    /// Start the attempt with an internal countdown driven by frame timestamps, so the
+    /// caller doesn't need to track wall-clock time itself and call `fail()` externally.
+    /// The start time is captured from the first frame timestamp processed after this call.
+    #[wasm_bindgen]
+    pub fn start_with_duration(&mut self, target: u32, duration_ms: f64) {
+        self.target = target;
+        self.start();
+        self.duration_ms = Some(duration_ms);
     }
 
     /// Reset the gate to idle state
@@ -126,6 +406,13 @@ impl GestureGate {
         self.can_score = true;
         self.current_stable_count = 0;
         self.pending_gesture = GestureType::None;
+        self.prev_landmarks = None;
+        self.motion_history.clear();
+        self.duration_ms = None;
+        self.start_time_ms = None;
+        self.remaining_ms = None;
+        self.held_since = None;
+        self.gesture_buffer.clear();
     }
 
     /// Mark as failed (called when timer expires)
@@ -134,6 +421,7 @@ impl GestureGate {
         if self.state == GateState::Running {
             self.state = GateState::Failed;
         }
+        self.gesture_buffer.clear();
     }
 
     /// Get current count
@@ -166,42 +454,72 @@ impl GestureGate {
         self.state == GateState::Running
     }
 
-    /// Process a single hand's landmarks (21 points from MediaPipe)
-    /// Returns a JS object with gesture, state, count, scored
+    /// Process a single hand's landmarks (21 points from MediaPipe).
+    /// `timestamp_ms` should be a monotonically increasing capture time (e.g.
+    /// `performance.now()`), used to drive dynamic motion gesture detection, the
+    /// internal countdown started by `start_with_duration`, and hold-to-confirm scoring.
+    /// `is_left` is MediaPipe's handedness label for this hand, if known; it's only
+    /// consulted when `set_require_handedness(true)` or `set_require_palm_up(true)`
+    /// has been called.
+    /// Returns a JS object with gesture, state, count, scored, remaining_ms,
+    /// confidence, latency_frames
     #[wasm_bindgen]
-    pub fn process_landmarks(&mut self, landmarks_js: JsValue) -> JsValue {
+    pub fn process_landmarks(&mut self, landmarks_js: JsValue, timestamp_ms: f64, is_left: Option<bool>) -> JsValue {
         let landmarks: Vec<Landmark> = match serde_wasm_bindgen::from_value(landmarks_js) {
             Ok(l) => l,
             Err(_) => {
+                // Still advance the countdown on a malformed frame so the internal
+                // clock keeps pace with real elapsed time instead of pausing, and
+                // report the last known `remaining_ms` instead of flickering to "no
+                // timer".
+                self.advance_countdown(timestamp_ms);
                 return serde_wasm_bindgen::to_value(&FrameResult {
                     gesture: GestureType::None.as_str().to_string(),
                     state: self.state.as_str().to_string(),
                     count: self.count,
                     scored: false,
+                    remaining_ms: self.remaining_ms,
+                    confidence: 0.0,
+                    latency_frames: self.lookahead_frames,
                 }).unwrap_or(JsValue::NULL);
             }
         };
 
-        let (gesture, scored) = self.process_landmarks_internal(&landmarks);
+        let (gesture, scored) = self.process_landmarks_internal(&landmarks, timestamp_ms, is_left);
 
         serde_wasm_bindgen::to_value(&FrameResult {
             gesture: gesture.as_str().to_string(),
             state: self.state.as_str().to_string(),
             count: self.count,
             scored,
+            remaining_ms: self.remaining_ms,
+            confidence: self.last_confidence,
+            latency_frames: self.lookahead_frames,
         }).unwrap_or(JsValue::NULL)
     }
 
-    /// Process raw landmark arrays (for optimization)
-    /// Expects flat arrays: [x0, y0, z0, x1, y1, z1, ...]
+    /// Process raw landmark arrays (for optimization).
+    /// Expects separate x and y arrays of at least 21 values each. `is_left` is threaded through
+    /// to `set_require_handedness` gating the same as in `process_landmarks`. Note this
+    /// entry point has no z coordinate, so `set_require_palm_up` can never pass here —
+    /// the palm-normal cross product degenerates to a flat plane and never reads as
+    /// facing up. Use `process_landmarks` if palm-orientation gating is enabled.
     #[wasm_bindgen]
-    pub fn process_raw(&mut self, xs: &[f32], ys: &[f32]) -> JsValue {
+    pub fn process_raw(&mut self, xs: &[f32], ys: &[f32], timestamp_ms: f64, is_left: Option<bool>) -> JsValue {
         if xs.len() < 21 || ys.len() < 21 {
+            // Still advance the countdown on a malformed frame so the internal
+            // clock keeps pace with real elapsed time instead of pausing, and
+            // report the last known `remaining_ms` instead of flickering to "no
+            // timer".
+            self.advance_countdown(timestamp_ms);
             return serde_wasm_bindgen::to_value(&FrameResult {
                 gesture: GestureType::None.as_str().to_string(),
                 state: self.state.as_str().to_string(),
                 count: self.count,
                 scored: false,
+                remaining_ms: self.remaining_ms,
+                confidence: 0.0,
+                latency_frames: self.lookahead_frames,
             }).unwrap_or(JsValue::NULL);
         }
 
@@ -213,25 +531,55 @@ impl GestureGate {
             })
             .collect();
 
-        let (gesture, scored) = self.process_landmarks_internal(&landmarks);
+        let (gesture, scored) = self.process_landmarks_internal(&landmarks, timestamp_ms, is_left);
 
         serde_wasm_bindgen::to_value(&FrameResult {
             gesture: gesture.as_str().to_string(),
             state: self.state.as_str().to_string(),
             count: self.count,
             scored,
+            remaining_ms: self.remaining_ms,
+            confidence: self.last_confidence,
+            latency_frames: self.lookahead_frames,
         }).unwrap_or(JsValue::NULL)
     }
 }
 
 impl GestureGate {
-    fn process_landmarks_internal(&mut self, landmarks: &[Landmark]) -> (GestureType, bool) {
+    fn process_landmarks_internal(
+        &mut self,
+        landmarks: &[Landmark],
+        timestamp_ms: f64,
+        is_left: Option<bool>,
+    ) -> (GestureType, bool) {
+        self.advance_countdown(timestamp_ms);
+
         if landmarks.len() < 21 {
+            self.last_confidence = 0.0;
             return (GestureType::None, false);
         }
 
-        // Detect current gesture
-        let gesture = self.detect_gesture(landmarks);
+        self.last_confidence = compute_confidence(landmarks);
+        if self.last_confidence < self.min_confidence {
+            // Degenerate/garbage frame: don't let it perturb smoothing, motion
+            // tracking, or stability/scoring state.
+            return (GestureType::None, false);
+        }
+
+        let filtered = self.apply_smoothing(landmarks);
+
+        // A dynamic motion gesture (swipe/pinch/rotate) takes priority over the
+        // static hand-pose gestures when one is in progress.
+        let motion = self.track_motion(&filtered, timestamp_ms);
+        let raw_gesture = if motion != GestureType::None {
+            motion
+        } else {
+            self.detect_gesture(&filtered, is_left)
+        };
+
+        // Hold the gesture back until a short lookahead window confirms it, so a
+        // transient mis-detection can be discarded before it ever scores.
+        let gesture = self.apply_lookahead(raw_gesture);
         let mut scored = false;
 
         // Update stability tracking
@@ -240,19 +588,32 @@ impl GestureGate {
         } else {
             self.pending_gesture = gesture;
             self.current_stable_count = if gesture != GestureType::None { 1 } else { 0 };
+            self.held_since = None;
         }
 
         // Only process scoring if running
         if self.state == GateState::Running {
-            // Check if we have a stable gesture
-            let stable_gesture = if self.current_stable_count >= self.stable_frames {
+            // Check if we have a stable gesture. Only the static hand-pose gestures
+            // ("6"/"7") drive scoring: dynamic motion gestures (swipe/pinch/rotate)
+            // are still reported in `FrameResult.gesture` for UI feedback, but must
+            // not unlock the gate in place of the documented pose.
+            let stable_gesture = if self.current_stable_count >= self.stable_frames
+                && matches!(gesture, GestureType::Six | GestureType::Seven)
+            {
                 gesture
             } else {
                 GestureType::None
             };
 
-            // Score if we have a valid stable gesture and can score
-            if stable_gesture != GestureType::None && self.can_score {
+            // Track the moment the gesture first became frame-stable, for hold-to-confirm
+            if stable_gesture != GestureType::None && self.held_since.is_none() {
+                self.held_since = Some(timestamp_ms);
+            }
+            let held_long_enough = self.hold_ms <= 0.0
+                || self.held_since.is_some_and(|since| timestamp_ms - since >= self.hold_ms);
+
+            // Score if we have a valid stable gesture, held long enough, and can score
+            if stable_gesture != GestureType::None && held_long_enough && self.can_score {
                 self.count += 1;
                 self.last_gesture = stable_gesture;
                 self.can_score = false;
@@ -273,8 +634,181 @@ impl GestureGate {
         (gesture, scored)
     }
 
+    /// Box/deadband filter for per-frame landmark jitter, applied before gesture detection.
+    /// Each coordinate is held at its previous filtered value while the new input stays
+    /// within `smoothing_radius` of it, and snapped to `input - sign(delta) * radius`
+    /// once it leaves that box. This removes small jitter without adding latency to
+    /// real finger movement, at the cost of a constant lag equal to the box radius.
+    fn apply_smoothing(&mut self, landmarks: &[Landmark]) -> Vec<Landmark> {
+        let filtered: Vec<Landmark> = match &self.prev_landmarks {
+            Some(prev) => landmarks
+                .iter()
+                .zip(prev.iter())
+                .map(|(cur, prev)| Landmark {
+                    x: box_filter(cur.x, prev.x, self.smoothing_radius),
+                    y: box_filter(cur.y, prev.y, self.smoothing_radius),
+                    z: box_filter(cur.z, prev.z, self.smoothing_radius),
+                })
+                .collect(),
+            None => landmarks.to_vec(),
+        };
+
+        self.prev_landmarks = Some(filtered.clone());
+        filtered
+    }
+
+    /// Advance the internal countdown started by `start_with_duration`, auto-failing
+    /// the attempt once the elapsed time reaches the configured duration, and updating
+    /// `remaining_ms` for the caller to display.
+    fn advance_countdown(&mut self, timestamp_ms: f64) {
+        self.remaining_ms = None;
+        if self.state != GateState::Running {
+            return;
+        }
+        let duration = match self.duration_ms {
+            Some(d) => d,
+            None => return,
+        };
+        let start = *self.start_time_ms.get_or_insert(timestamp_ms);
+        let elapsed = timestamp_ms - start;
+        self.remaining_ms = Some((duration - elapsed).max(0.0));
+        if elapsed >= duration {
+            self.state = GateState::Failed;
+        }
+    }
+
+    /// Delay the raw per-frame gesture by `lookahead_frames`, only letting it through
+    /// once every frame in the `[t, t+lookahead_frames]` window agrees with it.
+    /// A transient that doesn't hold for the whole window is suppressed as `None`.
+    fn apply_lookahead(&mut self, raw_gesture: GestureType) -> GestureType {
+        if self.lookahead_frames == 0 {
+            return raw_gesture;
+        }
+
+        self.gesture_buffer.push_back(raw_gesture);
+        let window = self.lookahead_frames as usize + 1;
+        while self.gesture_buffer.len() > window {
+            self.gesture_buffer.pop_front();
+        }
+        if self.gesture_buffer.len() < window {
+            // Not enough lookahead yet to confirm the oldest buffered frame.
+            return GestureType::None;
+        }
+
+        let candidate = *self.gesture_buffer.front().unwrap();
+        if self.gesture_buffer.iter().all(|g| *g == candidate) {
+            candidate
+        } else {
+            GestureType::None
+        }
+    }
+
+    /// Push the current frame onto the motion ring buffer and check whether the
+    /// window it spans now describes a swipe, pinch, or rotate.
+    fn track_motion(&mut self, lm: &[Landmark], timestamp_ms: f64) -> GestureType {
+        self.motion_history.push_back(MotionFrame {
+            timestamp_ms,
+            wrist: lm[0],
+            thumb_tip: lm[4],
+            index_tip: lm[8],
+        });
+        while let Some(oldest) = self.motion_history.front() {
+            if timestamp_ms - oldest.timestamp_ms > MOTION_WINDOW_MS {
+                self.motion_history.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        let oldest = match self.motion_history.front() {
+            Some(f) => *f,
+            None => return GestureType::None,
+        };
+        let newest = *self.motion_history.back().unwrap();
+        let elapsed_ms = newest.timestamp_ms - oldest.timestamp_ms;
+        if elapsed_ms < MOTION_MIN_WINDOW_MS {
+            return GestureType::None;
+        }
+        let elapsed_s = (elapsed_ms / 1000.0) as f32;
+
+        if let Some(swipe) = self.detect_swipe(oldest, newest, elapsed_s) {
+            return swipe;
+        }
+        if let Some(pinch) = self.detect_pinch(oldest, newest) {
+            return pinch;
+        }
+        if let Some(()) = self.detect_rotate(oldest, newest) {
+            return GestureType::Rotate;
+        }
+
+        GestureType::None
+    }
+
+    /// A swipe fires when the wrist's displacement over the window, divided by the
+    /// elapsed time, exceeds `swipe_vel_threshold` and the dominant axis of motion
+    /// is consistent (monotonic) across the whole window.
+    fn detect_swipe(&self, oldest: MotionFrame, newest: MotionFrame, elapsed_s: f32) -> Option<GestureType> {
+        let dx = newest.wrist.x - oldest.wrist.x;
+        let dy = newest.wrist.y - oldest.wrist.y;
+        let vx = dx / elapsed_s;
+        let vy = dy / elapsed_s;
+
+        let (axis_is_x, velocity) = if vx.abs() >= vy.abs() { (true, vx) } else { (false, vy) };
+        if velocity.abs() < self.swipe_vel_threshold {
+            return None;
+        }
+
+        let xs: Vec<f32> = self.motion_history.iter().map(|f| f.wrist.x).collect();
+        let ys: Vec<f32> = self.motion_history.iter().map(|f| f.wrist.y).collect();
+        if !is_monotonic(if axis_is_x { &xs } else { &ys }) {
+            return None;
+        }
+
+        Some(match (axis_is_x, velocity > 0.0) {
+            (true, true) => GestureType::SwipeRight,
+            (true, false) => GestureType::SwipeLeft,
+            // In screen/image coords y increases downward, so a positive dy is a downward swipe.
+            (false, true) => GestureType::SwipeDown,
+            (false, false) => GestureType::SwipeUp,
+        })
+    }
+
+    /// A pinch fires when the thumb tip <-> index tip distance changes by more than
+    /// `pinch_delta_threshold` as a fraction of its starting value across the window.
+    fn detect_pinch(&self, oldest: MotionFrame, newest: MotionFrame) -> Option<GestureType> {
+        let dist_at = |f: &MotionFrame| {
+            ((f.thumb_tip.x - f.index_tip.x).powi(2) + (f.thumb_tip.y - f.index_tip.y).powi(2)).sqrt()
+        };
+        let start = dist_at(&oldest);
+        let end = dist_at(&newest);
+        if start < f32::EPSILON {
+            return None;
+        }
+
+        let fraction = (end - start) / start;
+        if fraction <= -self.pinch_delta_threshold {
+            Some(GestureType::PinchIn)
+        } else if fraction >= self.pinch_delta_threshold {
+            Some(GestureType::PinchOut)
+        } else {
+            None
+        }
+    }
+
+    /// A rotate fires when the angle of the thumb->index vector changes by more than
+    /// `rotate_angle_threshold` radians (accumulated) across the window.
+    fn detect_rotate(&self, oldest: MotionFrame, newest: MotionFrame) -> Option<()> {
+        let angle_at = |f: &MotionFrame| (f.index_tip.y - f.thumb_tip.y).atan2(f.index_tip.x - f.thumb_tip.x);
+        let diff = angle_diff(angle_at(&oldest), angle_at(&newest));
+        if diff.abs() >= self.rotate_angle_threshold {
+            Some(())
+        } else {
+            None
+        }
+    }
+
     /// Detect gesture from landmarks using finger extension heuristics
-    fn detect_gesture(&self, lm: &[Landmark]) -> GestureType {
+    fn detect_gesture(&self, lm: &[Landmark], is_left: Option<bool>) -> GestureType {
         // MediaPipe landmark indices:
         // 0: wrist
         // 1-4: thumb (CMC, MCP, IP, TIP)
@@ -291,17 +825,72 @@ impl GestureGate {
 
         // Gesture "6": Thumb + Pinky extended, others folded
         // This represents "6" in some counting systems (thumb=5, pinky=1)
-        if thumb_extended && pinky_extended && !index_extended && !middle_extended && !ring_extended {
-            return GestureType::Six;
-        }
-
+        let candidate = if thumb_extended && pinky_extended && !index_extended && !middle_extended && !ring_extended {
+            GestureType::Six
         // Gesture "7": Index + Middle extended, others folded
         // This is the classic "peace sign" or "V" gesture
-        if index_extended && middle_extended && !thumb_extended && !ring_extended && !pinky_extended {
-            return GestureType::Seven;
+        } else if index_extended && middle_extended && !thumb_extended && !ring_extended && !pinky_extended {
+            GestureType::Seven
+        } else {
+            GestureType::None
+        };
+
+        if candidate == GestureType::None {
+            return GestureType::None;
         }
 
-        GestureType::None
+        // "6" is documented as the left hand, "7" as the right hand; reject the gesture
+        // if it's showing up mirrored on the wrong hand.
+        if self.require_handedness {
+            let expects_left = candidate == GestureType::Six;
+            match is_left {
+                Some(left) if left == expects_left => {}
+                _ => return GestureType::None,
+            }
+        }
+
+        if self.require_palm_up && !self.is_palm_up(lm, is_left) {
+            return GestureType::None;
+        }
+
+        candidate
+    }
+
+    /// Whether the palm is facing up/toward the camera, using the palm normal (cross
+    /// product of index-MCP and pinky-MCP relative to the wrist).
+    ///
+    /// The same physical "palm up" pose mirrored onto the opposite hand negates only
+    /// the x-components feeding the cross product, which flips the sign of the
+    /// resulting normal's y/z components while leaving x unchanged — so the up/down
+    /// reading is chirality-dependent. `is_left` corrects for that: the sign convention
+    /// below is calibrated for the left hand (and used as the default when handedness
+    /// is unknown), and flipped for the right hand so the same pose reads the same way
+    /// regardless of which hand performs it.
+    fn is_palm_up(&self, lm: &[Landmark], is_left: Option<bool>) -> bool {
+        let wrist = lm[0];
+        let index_mcp = lm[5];
+        let pinky_mcp = lm[17];
+
+        let v1 = (index_mcp.x - wrist.x, index_mcp.y - wrist.y, index_mcp.z - wrist.z);
+        let v2 = (pinky_mcp.x - wrist.x, pinky_mcp.y - wrist.y, pinky_mcp.z - wrist.z);
+
+        // Cross product v1 x v2 gives the palm normal.
+        let normal = (
+            v1.1 * v2.2 - v1.2 * v2.1,
+            v1.2 * v2.0 - v1.0 * v2.2,
+            v1.0 * v2.1 - v1.1 * v2.0,
+        );
+        let mag = (normal.0 * normal.0 + normal.1 * normal.1 + normal.2 * normal.2).sqrt();
+        if mag < f32::EPSILON {
+            return false;
+        }
+
+        // Image-space y grows downward, so "up" is the negative-y direction.
+        let mut up_component = -normal.1 / mag;
+        if is_left == Some(false) {
+            up_component = -up_component;
+        }
+        up_component >= PALM_UP_TOLERANCE
     }
 
     /// Check if thumb is extended
@@ -353,4 +942,342 @@ mod tests {
         gate.reset();
         assert_eq!(gate.get_state(), "idle");
     }
+
+    fn landmarks_with(wrist: Landmark, index_mcp: Landmark, pinky_mcp: Landmark) -> Vec<Landmark> {
+        let mut lm = vec![Landmark { x: 0.0, y: 0.0, z: 0.0 }; 21];
+        lm[0] = wrist;
+        lm[5] = index_mcp;
+        lm[17] = pinky_mcp;
+        lm
+    }
+
+    #[test]
+    fn test_palm_up_is_symmetric_across_handedness() {
+        let gate = GestureGate::new(1);
+        let wrist = Landmark { x: 0.0, y: 0.0, z: 0.0 };
+
+        // A left-hand pose with its palm normal pointing up.
+        let left_pose = landmarks_with(
+            wrist,
+            Landmark { x: 1.0, y: 0.0, z: 0.0 },
+            Landmark { x: 0.0, y: 0.0, z: 1.0 },
+        );
+        assert!(gate.is_palm_up(&left_pose, Some(true)));
+
+        // The same physical pose mirrored onto the right hand: only the x-components
+        // of the vectors feeding the cross product flip sign.
+        let right_pose = landmarks_with(
+            wrist,
+            Landmark { x: -1.0, y: 0.0, z: 0.0 },
+            Landmark { x: 0.0, y: 0.0, z: 1.0 },
+        );
+        assert!(gate.is_palm_up(&right_pose, Some(false)));
+    }
+
+    /// A "six" pose (thumb + pinky extended, others folded) that also passes the
+    /// hand-plausibility confidence check, used to test handedness/palm gating.
+    fn six_pose_landmarks() -> Vec<Landmark> {
+        let mut lm = vec![Landmark { x: 0.5, y: 0.6, z: 0.0 }; 21];
+        lm[0] = Landmark { x: 0.5, y: 0.9, z: 0.0 }; // wrist
+        lm[2] = Landmark { x: 0.5, y: 0.5, z: 0.0 }; // thumb MCP
+        lm[3] = Landmark { x: 0.52, y: 0.5, z: 0.0 }; // thumb IP
+        lm[4] = Landmark { x: 0.6, y: 0.5, z: 0.0 }; // thumb tip (extended)
+        lm[5] = Landmark { x: 0.4, y: 0.55, z: 0.0 }; // index MCP
+        lm[6] = Landmark { x: 0.4, y: 0.5, z: 0.0 }; // index PIP
+        lm[8] = Landmark { x: 0.4, y: 0.6, z: 0.0 }; // index tip (folded)
+        lm[9] = Landmark { x: 0.5, y: 0.55, z: 0.0 }; // middle MCP
+        lm[10] = Landmark { x: 0.5, y: 0.5, z: 0.0 }; // middle PIP
+        lm[12] = Landmark { x: 0.5, y: 0.6, z: 0.0 }; // middle tip (folded)
+        lm[13] = Landmark { x: 0.6, y: 0.55, z: 0.0 }; // ring MCP
+        lm[14] = Landmark { x: 0.6, y: 0.5, z: 0.0 }; // ring PIP
+        lm[16] = Landmark { x: 0.6, y: 0.6, z: 0.0 }; // ring tip (folded)
+        lm[17] = Landmark { x: 0.7, y: 0.55, z: 0.0 }; // pinky MCP
+        lm[18] = Landmark { x: 0.7, y: 0.55, z: 0.0 }; // pinky PIP
+        lm[20] = Landmark { x: 0.7, y: 0.3, z: 0.0 }; // pinky tip (extended)
+        lm
+    }
+
+    #[test]
+    fn test_handedness_gating_rejects_mirrored_hand() {
+        // `process_raw`/`process_landmarks` both delegate to this internal path with
+        // the caller's `is_left` label, so exercising it here also covers that wiring.
+        let mut gate = GestureGate::new(1);
+        gate.set_require_handedness(true);
+        gate.start();
+        let landmarks = six_pose_landmarks();
+
+        // "6" is documented as the left hand; showing up on the right hand is rejected.
+        let (gesture, _) = gate.process_landmarks_internal(&landmarks, 0.0, Some(false));
+        assert_eq!(gesture, GestureType::None);
+
+        let (gesture, _) = gate.process_landmarks_internal(&landmarks, 16.0, Some(true));
+        assert_eq!(gesture, GestureType::Six);
+    }
+
+    /// A plausible hand pose mid-`PinchIn`: thumb tip has converged onto the index
+    /// tip while the rest of the hand keeps normal knuckle spacing and silhouette.
+    fn tight_pinch_landmarks() -> Vec<Landmark> {
+        let mut lm = vec![Landmark { x: 0.5, y: 0.6, z: 0.0 }; 21];
+        lm[0] = Landmark { x: 0.5, y: 0.9, z: 0.0 }; // wrist
+        lm[5] = Landmark { x: 0.4, y: 0.55, z: 0.0 }; // index MCP
+        lm[9] = Landmark { x: 0.5, y: 0.55, z: 0.0 }; // middle MCP
+        lm[13] = Landmark { x: 0.6, y: 0.55, z: 0.0 }; // ring MCP
+        lm[17] = Landmark { x: 0.7, y: 0.55, z: 0.0 }; // pinky MCP
+        lm[4] = Landmark { x: 0.36, y: 0.31, z: 0.0 }; // thumb tip, pinched onto index tip
+        lm[8] = Landmark { x: 0.35, y: 0.3, z: 0.0 }; // index tip
+        lm[12] = Landmark { x: 0.45, y: 0.25, z: 0.0 }; // middle tip
+        lm[16] = Landmark { x: 0.55, y: 0.25, z: 0.0 }; // ring tip
+        lm[20] = Landmark { x: 0.65, y: 0.3, z: 0.0 }; // pinky tip
+        lm
+    }
+
+    #[test]
+    fn test_compute_confidence_survives_tight_pinch() {
+        let landmarks = tight_pinch_landmarks();
+
+        // Thumb tip sits well inside the old 5%-of-scale collision radius around the
+        // index tip here; this is the expected shape of a completed `PinchIn`, not a
+        // degenerate frame, so it must not drag confidence below the default
+        // `min_confidence` (0.5) used by `set_min_confidence`.
+        let scale = dist(landmarks[0], landmarks[9]);
+        assert!(dist(landmarks[4], landmarks[8]) / scale < MIN_FINGERTIP_SEPARATION_RATIO);
+
+        let confidence = compute_confidence(&landmarks);
+        assert!(
+            confidence >= 0.5,
+            "pinch frame confidence {confidence} fell below the default min_confidence"
+        );
+    }
+
+    #[test]
+    fn test_box_filter_holds_within_deadband() {
+        // A jump smaller than the radius is jitter: the filter should hold the
+        // previous value rather than let the noise through.
+        assert_eq!(box_filter(0.505, 0.5, 0.01), 0.5);
+    }
+
+    #[test]
+    fn test_box_filter_tracks_past_deadband() {
+        // A jump larger than the radius is real motion: the filter should track it,
+        // clamped to the nearest edge of the deadband rather than jumping all the way.
+        let result = box_filter(0.6, 0.5, 0.01);
+        assert!((result - 0.59).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_is_monotonic_accepts_increasing_and_decreasing() {
+        assert!(is_monotonic(&[0.1, 0.2, 0.3, 0.35]));
+        assert!(is_monotonic(&[0.35, 0.3, 0.2, 0.1]));
+    }
+
+    #[test]
+    fn test_is_monotonic_rejects_direction_reversal() {
+        assert!(!is_monotonic(&[0.1, 0.3, 0.2, 0.4]));
+    }
+
+    fn motion_frame(timestamp_ms: f64, wrist_x: f32, thumb_tip: Landmark, index_tip: Landmark) -> MotionFrame {
+        MotionFrame {
+            timestamp_ms,
+            wrist: Landmark { x: wrist_x, y: 0.5, z: 0.0 },
+            thumb_tip,
+            index_tip,
+        }
+    }
+
+    #[test]
+    fn test_detect_swipe_fires_on_fast_monotonic_wrist_motion() {
+        let gate = GestureGate::new(1);
+        let fixed_tip = Landmark { x: 0.0, y: 0.0, z: 0.0 };
+        let oldest = motion_frame(0.0, 0.0, fixed_tip, fixed_tip);
+        let newest = motion_frame(200.0, 0.3, fixed_tip, fixed_tip);
+        // dx = 0.3 over 0.2s => vx = 1.5, above the default 0.8 threshold.
+        assert_eq!(
+            gate.detect_swipe(oldest, newest, 0.2),
+            Some(GestureType::SwipeRight)
+        );
+    }
+
+    #[test]
+    fn test_detect_swipe_ignores_slow_motion() {
+        let gate = GestureGate::new(1);
+        let fixed_tip = Landmark { x: 0.0, y: 0.0, z: 0.0 };
+        let oldest = motion_frame(0.0, 0.0, fixed_tip, fixed_tip);
+        let newest = motion_frame(200.0, 0.05, fixed_tip, fixed_tip);
+        assert_eq!(gate.detect_swipe(oldest, newest, 0.2), None);
+    }
+
+    #[test]
+    fn test_detect_pinch_in_on_converging_tips() {
+        let gate = GestureGate::new(1);
+        let wrist = Landmark { x: 0.0, y: 0.0, z: 0.0 };
+        let oldest = MotionFrame {
+            timestamp_ms: 0.0,
+            wrist,
+            thumb_tip: Landmark { x: 0.0, y: 0.0, z: 0.0 },
+            index_tip: Landmark { x: 0.2, y: 0.0, z: 0.0 },
+        };
+        let newest = MotionFrame {
+            timestamp_ms: 200.0,
+            wrist,
+            thumb_tip: Landmark { x: 0.0, y: 0.0, z: 0.0 },
+            index_tip: Landmark { x: 0.05, y: 0.0, z: 0.0 },
+        };
+        assert_eq!(gate.detect_pinch(oldest, newest), Some(GestureType::PinchIn));
+    }
+
+    #[test]
+    fn test_detect_pinch_out_on_diverging_tips() {
+        let gate = GestureGate::new(1);
+        let wrist = Landmark { x: 0.0, y: 0.0, z: 0.0 };
+        let oldest = MotionFrame {
+            timestamp_ms: 0.0,
+            wrist,
+            thumb_tip: Landmark { x: 0.0, y: 0.0, z: 0.0 },
+            index_tip: Landmark { x: 0.05, y: 0.0, z: 0.0 },
+        };
+        let newest = MotionFrame {
+            timestamp_ms: 200.0,
+            wrist,
+            thumb_tip: Landmark { x: 0.0, y: 0.0, z: 0.0 },
+            index_tip: Landmark { x: 0.2, y: 0.0, z: 0.0 },
+        };
+        assert_eq!(gate.detect_pinch(oldest, newest), Some(GestureType::PinchOut));
+    }
+
+    #[test]
+    fn test_detect_rotate_fires_past_angle_threshold() {
+        let gate = GestureGate::new(1);
+        let wrist = Landmark { x: 0.0, y: 0.0, z: 0.0 };
+        // thumb->index vector starts pointing along +x, ends pointing along +y:
+        // a 90 degree swing, comfortably past the default 0.4 rad threshold.
+        let oldest = MotionFrame {
+            timestamp_ms: 0.0,
+            wrist,
+            thumb_tip: Landmark { x: 0.0, y: 0.0, z: 0.0 },
+            index_tip: Landmark { x: 1.0, y: 0.0, z: 0.0 },
+        };
+        let newest = MotionFrame {
+            timestamp_ms: 200.0,
+            wrist,
+            thumb_tip: Landmark { x: 0.0, y: 0.0, z: 0.0 },
+            index_tip: Landmark { x: 0.0, y: 1.0, z: 0.0 },
+        };
+        assert_eq!(gate.detect_rotate(oldest, newest), Some(()));
+    }
+
+    #[test]
+    fn test_detect_rotate_ignores_small_angle_change() {
+        let gate = GestureGate::new(1);
+        let wrist = Landmark { x: 0.0, y: 0.0, z: 0.0 };
+        let oldest = MotionFrame {
+            timestamp_ms: 0.0,
+            wrist,
+            thumb_tip: Landmark { x: 0.0, y: 0.0, z: 0.0 },
+            index_tip: Landmark { x: 1.0, y: 0.0, z: 0.0 },
+        };
+        let newest = MotionFrame {
+            timestamp_ms: 200.0,
+            wrist,
+            thumb_tip: Landmark { x: 0.0, y: 0.0, z: 0.0 },
+            index_tip: Landmark { x: 1.0, y: 0.05, z: 0.0 },
+        };
+        assert_eq!(gate.detect_rotate(oldest, newest), None);
+    }
+
+    #[test]
+    fn test_motion_gesture_does_not_drive_scoring() {
+        // A sustained swipe should keep being reported in `gesture`, but must never
+        // unlock the gate: only "6"/"7" may drive `count`/`target`/success.
+        let mut gate = GestureGate::new(1);
+        gate.start();
+
+        // Translate the whole hand together (not just the wrist) so the shape that
+        // `compute_confidence` checks stays constant while the hand as a whole moves.
+        let base = six_pose_landmarks();
+        let mut last_gesture = GestureType::None;
+        let mut any_scored = false;
+        for (i, t_ms) in [0.0, 200.0, 400.0, 600.0, 800.0].into_iter().enumerate() {
+            let dx = i as f32 * 0.2;
+            let lm: Vec<Landmark> = base.iter().map(|l| Landmark { x: l.x + dx, y: l.y, z: l.z }).collect();
+            let (gesture, scored) = gate.process_landmarks_internal(&lm, t_ms, None);
+            last_gesture = gesture;
+            any_scored |= scored;
+        }
+
+        assert_eq!(last_gesture, GestureType::SwipeRight);
+        assert!(!any_scored, "swipe should not score");
+        assert_eq!(gate.get_count(), 0);
+    }
+
+    #[test]
+    fn test_countdown_counts_down_and_expires() {
+        let mut gate = GestureGate::new(1);
+        gate.start_with_duration(1, 1000.0);
+        let empty: Vec<Landmark> = vec![];
+
+        gate.process_landmarks_internal(&empty, 0.0, None);
+        assert_eq!(gate.remaining_ms, Some(1000.0));
+        assert!(gate.is_running());
+
+        gate.process_landmarks_internal(&empty, 400.0, None);
+        assert_eq!(gate.remaining_ms, Some(600.0));
+        assert!(gate.is_running());
+
+        gate.process_landmarks_internal(&empty, 1000.0, None);
+        assert_eq!(gate.remaining_ms, Some(0.0));
+        assert!(gate.is_failed());
+    }
+
+    #[test]
+    fn test_hold_ms_delays_scoring_until_held_long_enough() {
+        let mut gate = GestureGate::new(5);
+        gate.set_hold_ms(100.0);
+        gate.start();
+        let landmarks = six_pose_landmarks();
+
+        // `stable_frames` (3 by default) consecutive matching frames are needed before
+        // the gesture is even eligible to score, and hold_ms hasn't elapsed yet either.
+        let mut scored_before_hold = false;
+        for t in [0.0, 10.0, 20.0] {
+            let (gesture, scored) = gate.process_landmarks_internal(&landmarks, t, None);
+            assert_eq!(gesture, GestureType::Six);
+            scored_before_hold |= scored;
+        }
+        assert!(!scored_before_hold, "should not score before hold_ms elapses");
+
+        // Same stable gesture, now well past hold_ms since it first became stable (t=20).
+        let (gesture, scored) = gate.process_landmarks_internal(&landmarks, 150.0, None);
+        assert_eq!(gesture, GestureType::Six);
+        assert!(scored, "should score once held for hold_ms");
+    }
+
+    #[test]
+    fn test_apply_lookahead_passes_through_when_disabled() {
+        let mut gate = GestureGate::new(1);
+        assert_eq!(gate.apply_lookahead(GestureType::Six), GestureType::Six);
+    }
+
+    #[test]
+    fn test_apply_lookahead_withholds_until_window_fills_then_confirms() {
+        let mut gate = GestureGate::new(1);
+        gate.set_lookahead(2); // window of 3 frames
+
+        assert_eq!(gate.apply_lookahead(GestureType::Six), GestureType::None);
+        assert_eq!(gate.apply_lookahead(GestureType::Six), GestureType::None);
+        // Third consecutive agreeing frame fills the window: the *first* buffered
+        // frame is now confirmed and released, at the cost of `lookahead_frames` lag.
+        assert_eq!(gate.apply_lookahead(GestureType::Six), GestureType::Six);
+    }
+
+    #[test]
+    fn test_apply_lookahead_suppresses_transient_flicker() {
+        let mut gate = GestureGate::new(1);
+        gate.set_lookahead(2); // window of 3 frames
+
+        assert_eq!(gate.apply_lookahead(GestureType::Six), GestureType::None);
+        // A single differing frame in the middle of the window should quench the
+        // whole window rather than let either gesture through.
+        assert_eq!(gate.apply_lookahead(GestureType::None), GestureType::None);
+        assert_eq!(gate.apply_lookahead(GestureType::Six), GestureType::None);
+    }
 }